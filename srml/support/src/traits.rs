@@ -17,11 +17,22 @@
 //! Traits for SRML
 
 use crate::rstd::result;
+use crate::rstd::marker::PhantomData;
 use crate::codec::{Codec, Encode, Decode};
 use crate::runtime_primitives::traits::{
 	MaybeSerializeDebug, SimpleArithmetic, As
 };
 
+/// A trait for querying a single fixed value from a type.
+///
+/// This is a zero-sized way of injecting a constant (e.g. a particular `CurrencyId`) as a type
+/// parameter, so it can be read back out at runtime without threading an extra value through
+/// every call site.
+pub trait Get<T> {
+	/// Return the current value.
+	fn get() -> T;
+}
+
 /// The account with the given id was killed.
 pub trait OnFreeBalanceZero<AccountId> {
 	/// The account was the given id was killed.
@@ -67,55 +78,53 @@ pub trait ArithmeticType {
 
 /// Simple trait designed for hooking into a transaction payment.
 ///
-/// It operates over a single generic `AccountId` type.
+/// It operates over a single generic `AccountId` type. Fee computation is split out into its own
+/// `compute_fee` step so that the exact same logic used to actually charge a transaction
+/// (`make_payment`) can also be used by an off-chain client to estimate the fee beforehand,
+/// without the two ever diverging.
 pub trait MakePayment<AccountId> {
+	/// The balance type the fee is denominated in.
+	type Balance;
+
+	/// Compute the fee payable for an extrinsic of encoded length `encoded_len` bytes.
+	fn compute_fee(encoded_len: usize) -> Self::Balance;
+
 	/// Make transaction payment from `who` for an extrinsic of encoded length
 	/// `encoded_len` bytes. Return `Ok` iff the payment was successful.
 	fn make_payment(who: &AccountId, encoded_len: usize) -> Result<(), &'static str>;
 }
 
 impl<T> MakePayment<T> for () {
-	fn make_payment(_: &T, _: usize) -> Result<(), &'static str> { Ok(()) }
-}
+	type Balance = ();
 
-/// Handler for when some currency "account" increased in balance for some reason.
-///
-/// The only reason at present would be for validator rewards, but there may be other
-/// reasons in the future or for other chains.
-///
-/// Typically just increases the total issuance of the currency, but could possibly
-/// draw down some other account.
-pub trait OnUnbalancedIncrease<Balance> {
-	/// Handler for the event.
-	///
-	/// May return an error if something "impossible" went wrong, but should be
-	/// infallible.
-	fn on_unbalanced_increase(amount: Balance) -> Result<(), &'static str>;
-}
+	fn compute_fee(_encoded_len: usize) -> Self::Balance { () }
 
-impl<B> OnUnbalancedIncrease<B> for () {
-	fn on_unbalanced_increase(_amount: B) -> Result<(), &'static str> { Ok(()) }
+	fn make_payment(_: &T, _: usize) -> Result<(), &'static str> { Ok(()) }
 }
 
-/// Handler for when some currency account decreased in balance for some reason.
-///
-/// Potential reasons are:
+/// Runtime API surface for querying balance information and fee predictions without dispatching a
+/// transaction.
 ///
-/// - Someone got slashed.
-/// - Someone paid for a transaction to be included.
-///
-/// Typically just reduces the total issuance of the currency, but could also pay
-/// into some other account.
-pub trait OnUnbalancedDecrease<Balance> {
-	/// Handler for the event.
-	///
-	/// May return an error if something "impossible" went wrong, but should be
-	/// infallible.
-	fn on_unbalanced_decrease(amount: Balance) -> Result<(), &'static str>;
-}
-
-impl<B> OnUnbalancedDecrease<B> for () {
-	fn on_unbalanced_decrease(_amount: B) -> Result<(), &'static str> { Ok(()) }
+/// This is intended to be wrapped in `decl_runtime_apis!` by the runtime that implements it. Its
+/// methods must reuse the exact same logic as the on-chain dry-run/charge path — `query_fee_details`
+/// calls straight through to `MakePayment::compute_fee`, and `can_withdraw` evaluates the same lock
+/// logic as `LockableCurrency` — so that what a client predicts off-chain can never diverge from
+/// what actually happens on-chain.
+pub trait BalancesApi<AccountId, Balance> {
+	/// Predict the fee for an extrinsic of encoded length `encoded_len` bytes, as it would be
+	/// computed by `MakePayment::compute_fee`.
+	fn query_fee_details(encoded_len: u32) -> Balance;
+
+	/// The 'free' balance of `who`. See `Currency::free_balance`.
+	fn free_balance(who: AccountId) -> Balance;
+
+	/// The balance of `who` that is actually free to move right now, i.e. `free_balance` minus
+	/// the most restrictive currently-active lock. See `LockableCurrency::usable_balance`.
+	fn usable_balance(who: AccountId) -> Balance;
+
+	/// Whether `who` could withdraw `value` for `reason` right now, taking active locks into
+	/// account.
+	fn can_withdraw(who: AccountId, value: Balance, reason: WithdrawReason) -> bool;
 }
 
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -124,11 +133,43 @@ pub enum ExistenceRequirement {
 	AllowDead,
 }
 
+/// An opaque token representing a balance that has been created or destroyed but not yet
+/// reconciled with `total_issuance`. Not `Clone`; implementors must give it a `Drop` impl that
+/// settles the default way (adjusting `total_issuance`) if it is not explicitly consumed first.
+pub trait Imbalance<Balance>: Sized {
+	/// The oppositely-signed imbalance type, e.g. `NegativeImbalance` for `PositiveImbalance`.
+	type Opposite: Imbalance<Balance, Opposite = Self>;
+
+	/// The zero imbalance. Can always be dropped with no effect on `total_issuance`.
+	fn zero() -> Self;
+
+	/// Consume `self` and return two non-negative imbalances whose values sum to the original;
+	/// the first will be at most `amount`.
+	fn split(self, amount: Balance) -> (Self, Self);
+
+	/// Consume `self` and `other`, returning a single imbalance whose value is their sum.
+	fn merge(self, other: Self) -> Self;
+
+	/// Consume `self` and an `Opposite` instance, netting them against each other.
+	///
+	/// If `self` is larger, returns `Ok` with the remaining `Self`. If `other` is larger (or they
+	/// are equal), returns `Err` with the remaining `Opposite`.
+	fn offset(self, other: Self::Opposite) -> result::Result<Self, Self::Opposite>;
+}
+
 /// Abstraction over a fungible assets system.
 pub trait Currency<AccountId> {
 	/// The balance of an account.
 	type Balance;
 
+	/// The opaque token type for an imbalance. This is returned by unbalanced operations
+	/// that increase total issuance, such as `reward`.
+	type PositiveImbalance: Imbalance<Self::Balance, Opposite = Self::NegativeImbalance>;
+
+	/// The opaque token type for an imbalance. This is returned by unbalanced operations
+	/// that decrease total issuance, such as `slash` or `withdraw`.
+	type NegativeImbalance: Imbalance<Self::Balance, Opposite = Self::PositiveImbalance>;
+
 	// PUBLIC IMMUTABLES
 
 	/// The combined balance of `who`.
@@ -138,10 +179,6 @@ pub trait Currency<AccountId> {
 	/// balance changes in the meantime and only the reserved balance is not taken into account.
 	fn can_slash(who: &AccountId, value: Self::Balance) -> bool;
 
-	/// Same result as `reserve(who, value)` (but without the side-effects) assuming there
-	/// are no balance changes in the meantime.
-	fn can_reserve(who: &AccountId, value: Self::Balance) -> bool;
-
 	/// The total amount of stake on the system.
 	fn total_issuance() -> Self::Balance;
 
@@ -162,59 +199,82 @@ pub trait Currency<AccountId> {
 	/// collapsed to zero if it ever becomes less than `ExistentialDeposit`.
 	fn free_balance(who: &AccountId) -> Self::Balance;
 
-	/// The amount of the balance of a given account that is externally reserved; this can still get
-	/// slashed, but gets slashed last of all.
-	///
-	/// This balance is a 'reserve' balance that other subsystems use in order to set aside tokens
-	/// that are still 'owned' by the account holder, but which are suspendable. (This is different
-	/// and wholly unrelated to the `Bondage` system used in the staking module.)
-	///
-	/// When this balance falls below the value of `ExistentialDeposit`, then this 'reserve account'
-	/// is deleted: specifically, `ReservedBalance`.
-	///
-	/// `system::AccountNonce` is also deleted if `FreeBalance` is also zero (it also gets
-	/// collapsed to zero if it ever becomes less than `ExistentialDeposit`.
-	fn reserved_balance(who: &AccountId) -> Self::Balance;
-
 	// PUBLIC MUTABLES (DANGEROUS)
 
+	/// Transfer some liquid free balance to another staker.
+	///
+	/// This is a very high-level function. It will ensure all appropriate fees are paid
+	/// and no imbalance in the system remains.
+	fn transfer(source: &AccountId, dest: &AccountId, value: Self::Balance) -> result::Result<(), &'static str>;
+
 	/// Deducts up to `value` from the combined balance of `who`, preferring to deduct from the
 	/// free balance. This function cannot fail.
 	///
-	/// As much funds up to `value` will be deducted as possible. If this is less than `value`,
-	/// then `Some(remaining)` will be returned. Full completion is given by `None`.
-	fn slash<S: OnUnbalancedDecrease<Self::Balance>>(
+	/// As much funds up to `value` will be deducted as possible. The resulting `NegativeImbalance`
+	/// represents the amount actually deducted; if this is less than `value`, the shortfall is
+	/// given as the second element. Callers must do something with the returned imbalance (for
+	/// example route it into a treasury account or `merge` it elsewhere) or let it drop, at which
+	/// point it will reduce `total_issuance` by its value.
+	fn slash(
 		who: &AccountId,
 		value: Self::Balance
-	) -> Option<Self::Balance>;
+	) -> (Self::NegativeImbalance, Self::Balance);
 
 	/// Mints `value` to the free balance of `who`.
 	///
-	/// If `who` doesn't exist, nothing is done and an Err returned.
-	fn reward<S: OnUnbalancedIncrease<Self::Balance>>(
+	/// If `who` doesn't exist, nothing is done and an Err returned. Otherwise returns a
+	/// `PositiveImbalance` for the amount minted, which the caller must consume; letting it drop
+	/// will credit `total_issuance` by its value.
+	fn reward(
 		who: &AccountId,
 		value: Self::Balance
-	) -> result::Result<(), &'static str>;
+	) -> result::Result<Self::PositiveImbalance, &'static str>;
 
 	/// Removes some free balance from `who` account for `reason` if possible. If `liveness` is `KeepAlive`,
 	/// then no less than `ExistentialDeposit` must be left remaining.
 	///
 	/// This checks any locks, vesting and liquidity requirements. If the removal is not possible, then it
-	/// returns `Err`.
-	fn withdraw<S: OnUnbalancedDecrease<Self::Balance>>(
+	/// returns `Err`. On success, returns the `NegativeImbalance` for the amount withdrawn, which the
+	/// caller must consume (for instance by merging it with a `slash`ed imbalance, or simply dropping it
+	/// to burn the funds).
+	fn withdraw(
 		who: &AccountId,
 		value: Self::Balance,
 		reason: WithdrawReason,
 		liveness: ExistenceRequirement,
-	) -> result::Result<(), &'static str>;
+	) -> result::Result<Self::NegativeImbalance, &'static str>;
 
-	/// Adds up to `value` to the free balance of `who`. If `who` doesn't exist, it is created
+	/// Adds up to `value` to the free balance of `who`. If `who` doesn't exist, it is created.
 	///
-	/// Returns if the account was successfully updated or update has led to killing of the account.
-	fn increase_free_balance_creating<S: OnUnbalancedIncrease<Self::Balance>>(
+	/// Returns whether the account was simply updated or the update has led to killing of the
+	/// account, along with the `PositiveImbalance` for the amount credited, which the caller must
+	/// consume; letting it drop will credit `total_issuance` by its value.
+	fn increase_free_balance_creating(
 		who: &AccountId,
 		value: Self::Balance
-	) -> UpdateBalanceOutcome;
+	) -> (UpdateBalanceOutcome, Self::PositiveImbalance);
+}
+
+/// A currency whose accounts can reserve balance, setting it aside for later repatriation or
+/// return.
+pub trait ReservableCurrency<AccountId>: Currency<AccountId> {
+	/// The amount of the balance of a given account that is externally reserved; this can still get
+	/// slashed, but gets slashed last of all.
+	///
+	/// This balance is a 'reserve' balance that other subsystems use in order to set aside tokens
+	/// that are still 'owned' by the account holder, but which are suspendable. (This is different
+	/// and wholly unrelated to the `Bondage` system used in the staking module.)
+	///
+	/// When this balance falls below the value of `ExistentialDeposit`, then this 'reserve account'
+	/// is deleted: specifically, `ReservedBalance`.
+	///
+	/// `system::AccountNonce` is also deleted if `FreeBalance` is also zero (it also gets
+	/// collapsed to zero if it ever becomes less than `ExistentialDeposit`.
+	fn reserved_balance(who: &AccountId) -> Self::Balance;
+
+	/// Same result as `reserve(who, value)` (but without the side-effects) assuming there
+	/// are no balance changes in the meantime.
+	fn can_reserve(who: &AccountId, value: Self::Balance) -> bool;
 
 	/// Moves `value` from balance to reserved balance.
 	///
@@ -231,12 +291,13 @@ pub trait Currency<AccountId> {
 
 	/// Deducts up to `value` from reserved balance of `who`. This function cannot fail.
 	///
-	/// As much funds up to `value` will be deducted as possible. If this is less than `value`,
-	/// then `Some(remaining)` will be returned. Full completion is given by `None`.
-	fn slash_reserved<S: OnUnbalancedDecrease<Self::Balance>>(
+	/// As much funds up to `value` will be deducted as possible, with the actually deducted
+	/// amount returned as a `NegativeImbalance` for the caller to consume. If this is less than
+	/// `value`, the shortfall is given as the second element.
+	fn slash_reserved(
 		who: &AccountId,
 		value: Self::Balance
-	) -> Option<Self::Balance>;
+	) -> (Self::NegativeImbalance, Self::Balance);
 
 	/// Moves up to `value` from reserved balance of account `slashed` to free balance of account
 	/// `beneficiary`. `beneficiary` must exist for this to succeed. If it does not, `Err` will be
@@ -251,10 +312,32 @@ pub trait Currency<AccountId> {
 	) -> result::Result<Option<Self::Balance>, &'static str>;
 }
 
+/// Bound satisfied automatically by any type implementing both `Currency` and
+/// `ReservableCurrency`, i.e. the old monolithic `Currency` surface. Blanket-implemented so
+/// existing combined implementations keep satisfying it unchanged.
+pub trait ReservableCurrencyAdapter<AccountId>: Currency<AccountId> + ReservableCurrency<AccountId> {}
+
+impl<AccountId, T: Currency<AccountId> + ReservableCurrency<AccountId>> ReservableCurrencyAdapter<AccountId> for T {}
+
 /// An identifier for a lock. Used for disambiguating different locks so that
 /// they can be individually replaced or removed.
 pub type LockIdentifier = [u8; 8];
 
+/// A single lock on a balance. There can be many of these on an account and they "overlap", so
+/// the same balance is frozen by multiple locks.
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct BalanceLock<Balance, Moment> {
+	/// An identifier for this lock. Only one lock may be in existence for each identifier.
+	pub id: LockIdentifier,
+	/// The amount which the free balance may not drop below when this lock is in effect.
+	pub amount: Balance,
+	/// The moment at which the lock automatically ends.
+	pub until: Moment,
+	/// The reasons for which this lock is in effect.
+	pub reasons: WithdrawReasons,
+}
+
 /// A currency whose accounts can have liquidity restrictions.
 pub trait LockableCurrency<AccountId>: Currency<AccountId> {
 	/// The quantity used to denote time; usually just a `BlockNumber`.
@@ -284,6 +367,46 @@ pub trait LockableCurrency<AccountId>: Currency<AccountId> {
 		id: LockIdentifier,
 		who: &AccountId,
 	);
+
+	/// The amount of `who`'s free balance that is actually free to move for an operation whose
+	/// `WithdrawReasons` are given by `reasons` right now, i.e. `free_balance` minus the largest
+	/// `amount` among all of `who`'s currently-active locks (those not yet past their `until`
+	/// moment) whose own `reasons` intersect `reasons`.
+	fn usable_balance(who: &AccountId, reasons: WithdrawReasons) -> Self::Balance;
+
+	/// The set of locks currently in effect for `who`.
+	fn locks(who: &AccountId) -> crate::rstd::vec::Vec<BalanceLock<Self::Balance, Self::Moment>>;
+}
+
+/// A `LockableCurrency` that also tracks a vesting schedule.
+pub trait VestingCurrency<AccountId>: LockableCurrency<AccountId> {
+	/// The amount of `who`'s balance that is still locked under its vesting schedule.
+	fn vesting_balance(who: &AccountId) -> Self::Balance;
+}
+
+/// A currency with a programmatically adjustable supply, for algorithmic stablecoin/peg-maintenance
+/// modules built on top of `Currency`.
+pub trait SettCurrency<AccountId>: Currency<AccountId> {
+	/// The minimum meaningful denomination, used as the peg target for `serp_tes`.
+	fn base_unit() -> Self::Balance;
+
+	/// Expand the supply by `amount`, minting new units. Bonds in the global FIFO queue (see
+	/// `contract_supply`) are redeemed oldest-first out of the new supply before any remainder is
+	/// credited to the configured distribution target.
+	fn expand_supply(amount: Self::Balance) -> result::Result<(), &'static str>;
+
+	/// Contract the supply by `amount`, taking it from `who`'s free balance and recording it as a
+	/// bond owed to `who` at the back of the global FIFO queue, to be redeemed by a future
+	/// `expand_supply` or `redeem_bonds`.
+	fn contract_supply(who: &AccountId, amount: Self::Balance) -> result::Result<(), &'static str>;
+
+	/// Redeem as much of `who`'s outstanding bonds as the queue has settled so far, crediting the
+	/// amount to their free balance and returning it.
+	fn redeem_bonds(who: &AccountId) -> result::Result<Self::Balance, &'static str>;
+
+	/// Given an oracle-reported `price`, compute its signed deviation from `base_unit` and call
+	/// `expand_supply`/`contract_supply` by a proportional amount.
+	fn serp_tes(price: Self::Balance);
 }
 
 bitmask! {
@@ -304,3 +427,197 @@ bitmask! {
 		Fee = 0b00001000,
 	}
 }
+
+/// Abstraction over a set of fungible assets, each identified by a `CurrencyId`. Every method
+/// takes a leading `currency_id` to pick out which asset it operates on.
+pub trait MultiCurrency<AccountId> {
+	/// The identifier used to distinguish between different currencies.
+	type CurrencyId;
+
+	/// The balance of an account under a given currency.
+	type Balance;
+
+	// PUBLIC IMMUTABLES
+
+	/// The combined balance of `who` under `currency_id`.
+	fn total_balance(currency_id: Self::CurrencyId, who: &AccountId) -> Self::Balance;
+
+	/// The 'free' balance of `who` under `currency_id`. See `Currency::free_balance`.
+	fn free_balance(currency_id: Self::CurrencyId, who: &AccountId) -> Self::Balance;
+
+	/// The total amount of `currency_id` issued in the system.
+	fn total_issuance(currency_id: Self::CurrencyId) -> Self::Balance;
+
+	/// The minimum balance any single account may have of `currency_id`.
+	fn minimum_balance(currency_id: Self::CurrencyId) -> Self::Balance;
+
+	/// Same result as `slash(currency_id, who, value)` (but without the side-effects) assuming
+	/// there are no balance changes in the meantime and only the reserved balance is not taken
+	/// into account.
+	fn can_slash(currency_id: Self::CurrencyId, who: &AccountId, value: Self::Balance) -> bool;
+
+	// PUBLIC MUTABLES (DANGEROUS)
+
+	/// Transfer `value` of `currency_id` from `source` to `destination`.
+	fn transfer(
+		currency_id: Self::CurrencyId,
+		source: &AccountId,
+		destination: &AccountId,
+		value: Self::Balance,
+	) -> result::Result<(), &'static str>;
+
+	/// Remove `value` of `currency_id` free balance from `who`.
+	fn withdraw(
+		currency_id: Self::CurrencyId,
+		who: &AccountId,
+		value: Self::Balance,
+	) -> result::Result<(), &'static str>;
+
+	/// Add `value` of `currency_id` to the free balance of `who`.
+	fn deposit(
+		currency_id: Self::CurrencyId,
+		who: &AccountId,
+		value: Self::Balance,
+	) -> result::Result<(), &'static str>;
+
+	/// Deduct up to `value` of `currency_id` from the combined balance of `who`. As much as
+	/// possible is deducted; the amount that could not be deducted is returned.
+	fn slash(currency_id: Self::CurrencyId, who: &AccountId, value: Self::Balance) -> Self::Balance;
+}
+
+/// A `MultiCurrency` whose accounts can have liquidity restrictions on a per-currency basis.
+pub trait MultiLockableCurrency<AccountId>: MultiCurrency<AccountId> {
+	/// The quantity used to denote time; usually just a `BlockNumber`.
+	type Moment;
+
+	/// Introduce a new lock or change an existing one, under `currency_id`.
+	fn set_lock(
+		id: LockIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &AccountId,
+		amount: Self::Balance,
+		until: Self::Moment,
+		reasons: WithdrawReasons,
+	);
+
+	/// Change any existing lock so that it becomes strictly less liquid in all respects to the
+	/// given parameters, under `currency_id`.
+	fn extend_lock(
+		id: LockIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &AccountId,
+		amount: Self::Balance,
+		until: Self::Moment,
+		reasons: WithdrawReasons,
+	);
+
+	/// Remove an existing lock, under `currency_id`.
+	fn remove_lock(id: LockIdentifier, currency_id: Self::CurrencyId, who: &AccountId);
+}
+
+/// A `MultiCurrency` whose accounts support reservation on a per-currency basis.
+pub trait MultiReservableCurrency<AccountId>: MultiCurrency<AccountId> {
+	/// Same result as `reserve(currency_id, who, value)` (but without the side-effects) assuming
+	/// there are no balance changes in the meantime.
+	fn can_reserve(currency_id: Self::CurrencyId, who: &AccountId, value: Self::Balance) -> bool;
+
+	/// The amount of `currency_id` balance of `who` that is externally reserved.
+	fn reserved_balance(currency_id: Self::CurrencyId, who: &AccountId) -> Self::Balance;
+
+	/// Move `value` of `currency_id` from balance to reserved balance.
+	fn reserve(
+		currency_id: Self::CurrencyId,
+		who: &AccountId,
+		value: Self::Balance,
+	) -> result::Result<(), &'static str>;
+
+	/// Move up to `value` of `currency_id` from reserved balance to balance. Returns the amount
+	/// that could not be unreserved.
+	fn unreserve(currency_id: Self::CurrencyId, who: &AccountId, value: Self::Balance) -> Self::Balance;
+
+	/// Move up to `value` of `currency_id` reserved balance of account `slashed` to free balance
+	/// of account `beneficiary`. Returns the amount that could not be repatriated.
+	fn repatriate_reserved(
+		currency_id: Self::CurrencyId,
+		slashed: &AccountId,
+		beneficiary: &AccountId,
+		value: Self::Balance,
+	) -> result::Result<Self::Balance, &'static str>;
+}
+
+/// Adapter that lifts a single-asset `Currency<AccountId>` implementation, `C`, into a
+/// `MultiCurrency` that only ever recognises one fixed `CurrencyId`, supplied by `GetCurrencyId`.
+pub struct CurrencyAdapter<C, GetCurrencyId, CurrencyId>(PhantomData<(C, GetCurrencyId, CurrencyId)>);
+
+impl<AccountId, C, GetCurrencyId, CurrencyId> MultiCurrency<AccountId>
+	for CurrencyAdapter<C, GetCurrencyId, CurrencyId>
+where
+	C: Currency<AccountId>,
+	C::Balance: Default,
+	GetCurrencyId: Get<CurrencyId>,
+	CurrencyId: PartialEq,
+{
+	type CurrencyId = CurrencyId;
+	type Balance = C::Balance;
+
+	fn total_balance(currency_id: Self::CurrencyId, who: &AccountId) -> Self::Balance {
+		if currency_id != GetCurrencyId::get() { return Default::default(); }
+		C::total_balance(who)
+	}
+
+	fn free_balance(currency_id: Self::CurrencyId, who: &AccountId) -> Self::Balance {
+		if currency_id != GetCurrencyId::get() { return Default::default(); }
+		C::free_balance(who)
+	}
+
+	fn total_issuance(currency_id: Self::CurrencyId) -> Self::Balance {
+		if currency_id != GetCurrencyId::get() { return Default::default(); }
+		C::total_issuance()
+	}
+
+	fn minimum_balance(currency_id: Self::CurrencyId) -> Self::Balance {
+		if currency_id != GetCurrencyId::get() { return Default::default(); }
+		C::minimum_balance()
+	}
+
+	fn can_slash(currency_id: Self::CurrencyId, who: &AccountId, value: Self::Balance) -> bool {
+		if currency_id != GetCurrencyId::get() { return false; }
+		C::can_slash(who, value)
+	}
+
+	fn transfer(
+		currency_id: Self::CurrencyId,
+		source: &AccountId,
+		destination: &AccountId,
+		value: Self::Balance,
+	) -> result::Result<(), &'static str> {
+		if currency_id != GetCurrencyId::get() { return Err("unknown currency_id"); }
+		C::transfer(source, destination, value)
+	}
+
+	fn withdraw(
+		currency_id: Self::CurrencyId,
+		who: &AccountId,
+		value: Self::Balance,
+	) -> result::Result<(), &'static str> {
+		if currency_id != GetCurrencyId::get() { return Err("unknown currency_id"); }
+		C::withdraw(who, value, WithdrawReason::Transfer.into(), ExistenceRequirement::AllowDead)
+			.map(|_| ())
+	}
+
+	fn deposit(
+		currency_id: Self::CurrencyId,
+		who: &AccountId,
+		value: Self::Balance,
+	) -> result::Result<(), &'static str> {
+		if currency_id != GetCurrencyId::get() { return Err("unknown currency_id"); }
+		C::increase_free_balance_creating(who, value);
+		Ok(())
+	}
+
+	fn slash(currency_id: Self::CurrencyId, who: &AccountId, value: Self::Balance) -> Self::Balance {
+		if currency_id != GetCurrencyId::get() { return value; }
+		let (_, remaining) = C::slash(who, value);
+		remaining
+	}
+}